@@ -0,0 +1,394 @@
+//! Long-lived `python3 backend/main.py --serve` child process and the
+//! newline-delimited JSON-RPC protocol used to talk to it.
+//!
+//! Instead of paying interpreter/ORM startup cost on every command, the
+//! process is spawned once in `tauri::Builder::setup` and kept in managed
+//! state behind a `Mutex`. Requests are written to the child's stdin as a
+//! single JSON line; a background reader task demultiplexes responses from
+//! stdout by `id` so multiple commands can have requests in flight at once.
+//! Lines are decoded with `String::from_utf8_lossy` rather than strict UTF-8
+//! so a stray non-UTF-8 byte in an imported spreadsheet cell can't take down
+//! the whole connection.
+//!
+//! A background health-check task watches `alive` and respawns the child if
+//! it ever dies; any request still in flight at the time of a crash is
+//! failed immediately rather than left hanging forever.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tauri::{AppHandle, Manager};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::sync::oneshot;
+
+use crate::data_backend::DataBackend;
+use crate::debug_log::DebugLog;
+use crate::error::BackendError;
+
+/// How often the health-check task polls for a dead child process.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Serialize)]
+struct Request {
+    id: u64,
+    method: String,
+    params: Value,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    id: u64,
+    #[serde(default)]
+    result: Option<Value>,
+    #[serde(default)]
+    error: Option<Value>,
+}
+
+/// A `{"progress": {"done", "total", "phase"}}` line streamed by the backend
+/// while a long-running command (e.g. a spec import) is still in flight.
+#[derive(Deserialize, Serialize, Clone)]
+pub struct ImportProgress {
+    pub done: u64,
+    pub total: u64,
+    pub phase: String,
+}
+
+#[derive(Deserialize)]
+struct ProgressLine {
+    progress: ImportProgress,
+}
+
+type PendingMap = Arc<Mutex<HashMap<u64, oneshot::Sender<Result<Value, BackendError>>>>>;
+
+/// The child process plus the stdin handle used to write to it; replaced as
+/// a unit whenever the health-check task restarts a dead backend.
+struct ProcessState {
+    child: Child,
+    stdin: std::process::ChildStdin,
+}
+
+impl Drop for ProcessState {
+    /// Kills and reaps `child` so a restart (or app shutdown) never leaves
+    /// an orphaned `python3` process behind — the old `ProcessState` is
+    /// dropped the moment the health-check task overwrites it with a fresh
+    /// one, so this fires on every restart as well as on final teardown.
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Handle to the spawned backend process, held in Tauri managed state.
+///
+/// Cloning is cheap; every clone talks to the same child process (and, after
+/// a restart, the same replacement child).
+#[derive(Clone)]
+pub struct BackendProcess {
+    app: AppHandle,
+    state: Arc<Mutex<ProcessState>>,
+    pending: PendingMap,
+    next_id: Arc<AtomicU64>,
+    alive: Arc<AtomicBool>,
+    #[cfg_attr(not(feature = "debug"), allow(dead_code))]
+    debug_log: Arc<DebugLog>,
+}
+
+impl BackendProcess {
+    /// Spawns the backend child, starts its reader tasks, and starts the
+    /// health-check task that restarts it if it ever dies.
+    ///
+    /// `debug_log` is always wired up, but only ever gains entries when the
+    /// `debug` feature is enabled (see [`Self::request`] and the stderr
+    /// tee in [`spawn_child`]) — the default build pays for an empty ring
+    /// buffer and nothing else.
+    pub fn spawn(app: &AppHandle, debug_log: Arc<DebugLog>) -> std::io::Result<Self> {
+        let pending: PendingMap = Arc::new(Mutex::new(HashMap::new()));
+        let alive = Arc::new(AtomicBool::new(true));
+
+        let state = spawn_child(app, &pending, &alive, &debug_log)?;
+
+        let this = Self {
+            app: app.clone(),
+            state: Arc::new(Mutex::new(state)),
+            pending,
+            next_id: Arc::new(AtomicU64::new(1)),
+            alive,
+            debug_log,
+        };
+
+        this.spawn_health_check_task();
+        Ok(this)
+    }
+
+    /// Periodically checks whether the child is still alive and respawns it
+    /// if not. This is the "restart on crash" half of the backend-process
+    /// subsystem; [`Self::request`] handles the "in-flight requests don't
+    /// hang" half.
+    fn spawn_health_check_task(&self) {
+        let this = self.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(HEALTH_CHECK_INTERVAL).await;
+                if this.is_alive() {
+                    continue;
+                }
+                log::warn!("backend process is down, attempting restart");
+                match spawn_child(&this.app, &this.pending, &this.alive, &this.debug_log) {
+                    Ok(state) => {
+                        *this.state.lock().unwrap() = state;
+                        this.alive.store(true, Ordering::SeqCst);
+                        log::info!("backend process restarted");
+                    }
+                    Err(e) => {
+                        log::error!("failed to restart backend process: {e}");
+                    }
+                }
+            }
+        });
+    }
+
+    /// Sends `method`/`params` to the backend and awaits the response with
+    /// the matching `id`.
+    pub async fn request(&self, method: &str, params: Value) -> Result<Value, BackendError> {
+        if !self.alive.load(Ordering::SeqCst) {
+            return Err(BackendError::transport(
+                "backend process is not running (awaiting restart)",
+            ));
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().unwrap().insert(id, tx);
+
+        let request = Request {
+            id,
+            method: method.to_string(),
+            params,
+        };
+        let mut line =
+            serde_json::to_string(&request).map_err(|e| BackendError::protocol(e.to_string()))?;
+
+        #[cfg(feature = "debug")]
+        {
+            log::debug!("[backend request] {line}");
+            self.debug_log.push(format!("-> {line}"));
+        }
+
+        line.push('\n');
+
+        {
+            let mut state = self.state.lock().unwrap();
+            if let Err(e) = state.stdin.write_all(line.as_bytes()) {
+                self.pending.lock().unwrap().remove(&id);
+                self.alive.store(false, Ordering::SeqCst);
+                return Err(BackendError::transport(e.to_string()));
+            }
+        }
+
+        match rx.await {
+            Ok(result) => result,
+            Err(_) => Err(BackendError::transport(
+                "backend process closed the connection",
+            )),
+        }
+    }
+
+    /// True if the child process is currently believed to be alive.
+    pub fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl DataBackend for BackendProcess {
+    async fn import_master_spec(
+        &self,
+        project_id: i32,
+        file_path: PathBuf,
+    ) -> Result<usize, BackendError> {
+        let result = self
+            .request(
+                "import_master_spec",
+                json!({ "project_id": project_id, "file_path": file_path }),
+            )
+            .await?;
+        serde_json::from_value(result).map_err(|e| BackendError::protocol(e.to_string()))
+    }
+
+    async fn import_supplier_feedback(
+        &self,
+        project_id: i32,
+        iteration_id: String,
+        supplier_name: String,
+        file_path: PathBuf,
+    ) -> Result<usize, BackendError> {
+        let result = self
+            .request(
+                "import_supplier_feedback",
+                json!({
+                    "project_id": project_id,
+                    "iteration_id": iteration_id,
+                    "supplier_name": supplier_name,
+                    "file_path": file_path,
+                }),
+            )
+            .await?;
+        serde_json::from_value(result).map_err(|e| BackendError::protocol(e.to_string()))
+    }
+
+    async fn get_cockpit_data(
+        &self,
+        project_id: i32,
+        iteration_id: i32,
+    ) -> Result<Value, BackendError> {
+        self.request(
+            "get_cockpit_data",
+            json!({ "project_id": project_id, "iteration_id": iteration_id }),
+        )
+        .await
+    }
+
+    async fn list_recent_projects(&self, app_data_dir: PathBuf) -> Result<Value, BackendError> {
+        self.request(
+            "list_recent_projects",
+            json!({ "app_data_dir": app_data_dir }),
+        )
+        .await
+    }
+
+    async fn create_project(
+        &self,
+        app_data_dir: PathBuf,
+        name: String,
+        path: String,
+    ) -> Result<Value, BackendError> {
+        self.request(
+            "create_project",
+            json!({ "app_data_dir": app_data_dir, "name": name, "path": path }),
+        )
+        .await
+    }
+
+    async fn project_schema_status(&self, project_id: i32) -> Result<Value, BackendError> {
+        self.request(
+            "get_project_schema_status",
+            json!({ "project_id": project_id }),
+        )
+        .await
+    }
+}
+
+/// Spawns the `python3 backend/main.py --serve` child and its stdout/stderr
+/// reader tasks. On stdout EOF (the child died or closed the pipe), every
+/// request still waiting in `pending` is failed immediately instead of
+/// being left to hang, and `alive` is cleared so the health-check task picks
+/// up the restart.
+fn spawn_child(
+    app: &AppHandle,
+    pending: &PendingMap,
+    alive: &Arc<AtomicBool>,
+    debug_log: &Arc<DebugLog>,
+) -> std::io::Result<ProcessState> {
+    let mut child = Command::new("python3")
+        .arg("backend/main.py")
+        .arg("--serve")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stdin = child.stdin.take().expect("backend child stdin was piped");
+    let stdout = child.stdout.take().expect("backend child stdout was piped");
+    let stderr = child.stderr.take().expect("backend child stderr was piped");
+
+    let reader_pending = pending.clone();
+    let reader_app = app.clone();
+    let reader_alive = alive.clone();
+
+    tokio::spawn(async move {
+        read_lines_lossy(stdout, |line| {
+            handle_line(&line, &reader_pending, &reader_app);
+        })
+        .await;
+
+        reader_alive.store(false, Ordering::SeqCst);
+        fail_all_pending(
+            &reader_pending,
+            "backend process closed the connection",
+        );
+    });
+
+    #[cfg(feature = "debug")]
+    {
+        let stderr_log = debug_log.clone();
+        tokio::spawn(async move {
+            read_lines_lossy(stderr, |line| {
+                log::debug!("[backend stderr] {line}");
+                stderr_log.push(format!("stderr: {line}"));
+            })
+            .await;
+        });
+    }
+    #[cfg(not(feature = "debug"))]
+    {
+        let _ = debug_log;
+        drop(stderr);
+    }
+
+    Ok(ProcessState { child, stdin })
+}
+
+/// Fails every in-flight request with `message` instead of leaving its
+/// `oneshot::Sender` parked in `pending` forever.
+fn fail_all_pending(pending: &PendingMap, message: &str) {
+    for (_, tx) in pending.lock().unwrap().drain() {
+        let _ = tx.send(Err(BackendError::transport(message)));
+    }
+}
+
+/// Reads `reader` line-by-line, decoding each line with
+/// `String::from_utf8_lossy`, and invokes `on_line` for every non-empty one.
+async fn read_lines_lossy<R: AsyncRead + Unpin>(reader: R, mut on_line: impl FnMut(String)) {
+    let mut reader = BufReader::new(reader);
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        match reader.read_until(b'\n', &mut buf).await {
+            Ok(0) => break,
+            Ok(_) => {
+                let line = String::from_utf8_lossy(&buf);
+                let line = line.trim_end_matches(['\n', '\r']);
+                if !line.is_empty() {
+                    on_line(line.to_string());
+                }
+            }
+            Err(_) => break,
+        }
+    }
+}
+
+fn handle_line(line: &str, pending: &PendingMap, app: &AppHandle) {
+    if let Ok(progress) = serde_json::from_str::<ProgressLine>(line) {
+        let _ = app.emit_all("import-progress", progress.progress);
+        return;
+    }
+
+    let Ok(resp) = serde_json::from_str::<Response>(line) else {
+        return;
+    };
+    if let Some(tx) = pending.lock().unwrap().remove(&resp.id) {
+        let result = match resp.error {
+            Some(err) => Err(BackendError::from_backend_value(err)),
+            None => Ok(resp.result.unwrap_or(Value::Null)),
+        };
+        let _ = tx.send(result);
+    }
+}
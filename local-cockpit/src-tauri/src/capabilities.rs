@@ -0,0 +1,222 @@
+//! Per-project import scopes, modeled on Tauri's own capabilities system:
+//! each project declares the directories it's allowed to read import files
+//! from, and commands must prove a requested path canonicalizes inside one
+//! of them before it's ever handed to the backend.
+//!
+//! Scopes are declared in capability files (`capabilities/*.json` next to
+//! the app resources) so a locked-down deployment can ship a config that
+//! restricts imports to, say, a single drop folder, without a rebuild.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use tauri::AppHandle;
+
+use crate::error::BackendError;
+
+#[derive(Deserialize)]
+struct CapabilityFile {
+    project_id: i32,
+    allowed_dirs: Vec<PathBuf>,
+}
+
+/// Resolved, canonicalized import scopes for every project, loaded once at
+/// startup and held in Tauri managed state.
+pub struct CapabilityStore {
+    scopes: HashMap<i32, Vec<PathBuf>>,
+}
+
+impl CapabilityStore {
+    /// Loads every `capabilities/*.json` file shipped alongside the app
+    /// resources. A project with no matching file gets an empty scope list,
+    /// i.e. all imports for it are rejected.
+    pub fn load(app: &AppHandle) -> Self {
+        let mut scopes: HashMap<i32, Vec<PathBuf>> = HashMap::new();
+
+        if let Some(dir) = app
+            .path_resolver()
+            .resolve_resource("capabilities")
+            .filter(|dir| dir.is_dir())
+        {
+            if let Ok(entries) = fs::read_dir(dir) {
+                for entry in entries.flatten() {
+                    let path = entry.path();
+                    if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                        continue;
+                    }
+                    let Ok(contents) = fs::read_to_string(&path) else {
+                        continue;
+                    };
+                    let Ok(file) = serde_json::from_str::<CapabilityFile>(&contents) else {
+                        continue;
+                    };
+                    let canonical_dirs = file
+                        .allowed_dirs
+                        .iter()
+                        .filter_map(|d| fs::canonicalize(d).ok())
+                        .collect();
+                    scopes.entry(file.project_id).or_insert_with(Vec::new).extend(canonical_dirs);
+                }
+            }
+        }
+
+        Self { scopes }
+    }
+
+    /// Canonicalizes `file_path` and checks it falls inside one of
+    /// `project_id`'s allowed import directories. Returns the canonical
+    /// path on success so callers never hand the backend an unresolved,
+    /// possibly-symlinked path.
+    pub fn check(&self, project_id: i32, file_path: &str) -> Result<PathBuf, BackendError> {
+        let canonical = fs::canonicalize(Path::new(file_path))
+            .map_err(|e| BackendError::unauthorized(format!("cannot resolve '{file_path}': {e}")))?;
+
+        let allowed = self
+            .scopes
+            .get(&project_id)
+            .map(|dirs| dirs.iter().any(|dir| canonical.starts_with(dir)))
+            .unwrap_or(false);
+
+        if allowed {
+            Ok(canonical)
+        } else {
+            Err(BackendError::unauthorized(format!(
+                "'{file_path}' is outside project {project_id}'s allowed import scopes"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::BackendErrorKind;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static TEST_ID: AtomicU32 = AtomicU32::new(0);
+
+    /// A fresh, empty temp directory for one test, cleaned up on drop so
+    /// tests don't leave litter (or collide with each other) in `/tmp`.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            let id = TEST_ID.fetch_add(1, Ordering::SeqCst);
+            let path = std::env::temp_dir().join(format!(
+                "reqcockpit-capabilities-test-{}-{id}",
+                std::process::id()
+            ));
+            fs::create_dir_all(&path).expect("create temp dir");
+            Self(path)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn store_with_scope(project_id: i32, allowed_dir: &Path) -> CapabilityStore {
+        let mut scopes = HashMap::new();
+        scopes.insert(
+            project_id,
+            vec![fs::canonicalize(allowed_dir).expect("canonicalize scope dir")],
+        );
+        CapabilityStore { scopes }
+    }
+
+    #[test]
+    fn rejects_path_outside_any_scope() {
+        let scope = TempDir::new();
+        let outside = TempDir::new();
+        let file = outside.path().join("spec.csv");
+        fs::write(&file, "req_id,text").unwrap();
+
+        let store = store_with_scope(1, scope.path());
+
+        let err = store
+            .check(1, file.to_str().unwrap())
+            .expect_err("path outside scope must be rejected");
+        assert!(matches!(err.kind, BackendErrorKind::Unauthorized));
+    }
+
+    #[test]
+    fn allows_path_inside_scope() {
+        let scope = TempDir::new();
+        let file = scope.path().join("spec.csv");
+        fs::write(&file, "req_id,text").unwrap();
+
+        let store = store_with_scope(1, scope.path());
+
+        let resolved = store
+            .check(1, file.to_str().unwrap())
+            .expect("path inside scope must be allowed");
+        assert_eq!(resolved, fs::canonicalize(&file).unwrap());
+    }
+
+    #[test]
+    fn rejects_dotdot_traversal_out_of_scope() {
+        let scope = TempDir::new();
+        let outside = TempDir::new();
+        let secret = outside.path().join("secret.csv");
+        fs::write(&secret, "req_id,text").unwrap();
+
+        let store = store_with_scope(1, scope.path());
+
+        // Escapes the allowed dir via `..` even though the string starts
+        // inside it.
+        let traversal = scope
+            .path()
+            .join("..")
+            .join(outside.path().file_name().unwrap())
+            .join("secret.csv");
+
+        let err = store
+            .check(1, traversal.to_str().unwrap())
+            .expect_err("`..` traversal out of scope must be rejected");
+        assert!(matches!(err.kind, BackendErrorKind::Unauthorized));
+    }
+
+    #[test]
+    fn rejects_symlink_escaping_scope() {
+        let scope = TempDir::new();
+        let outside = TempDir::new();
+        let secret = outside.path().join("secret.csv");
+        fs::write(&secret, "req_id,text").unwrap();
+
+        let link = scope.path().join("innocuous.csv");
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&secret, &link).expect("create symlink");
+        #[cfg(not(unix))]
+        std::os::windows::fs::symlink_file(&secret, &link).expect("create symlink");
+
+        let store = store_with_scope(1, scope.path());
+
+        let err = store
+            .check(1, link.to_str().unwrap())
+            .expect_err("a symlink resolving outside scope must be rejected");
+        assert!(matches!(err.kind, BackendErrorKind::Unauthorized));
+    }
+
+    #[test]
+    fn rejects_unknown_project() {
+        let scope = TempDir::new();
+        let file = scope.path().join("spec.csv");
+        fs::write(&file, "req_id,text").unwrap();
+
+        // Scope is declared for project 1, not project 2.
+        let store = store_with_scope(1, scope.path());
+
+        let err = store
+            .check(2, file.to_str().unwrap())
+            .expect_err("a project with no declared scope must reject everything");
+        assert!(matches!(err.kind, BackendErrorKind::Unauthorized));
+    }
+}
@@ -0,0 +1,99 @@
+//! Thin `#[tauri::command]` wrappers over [`DataBackend`], which is backed
+//! by the persistent Python process (desktop) or the native `rusqlite` data
+//! layer (mobile / `native-backend` builds) — see [`crate::backend`] and
+//! [`crate::native_backend`]. Commands never know which one they're talking
+//! to.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::capabilities::CapabilityStore;
+use crate::data_backend::DataBackend;
+use crate::debug_log::DebugLog;
+use crate::error::BackendError;
+
+type Backend<'r> = State<'r, Arc<dyn DataBackend>>;
+
+#[tauri::command]
+pub async fn import_master_spec(
+    backend: Backend<'_>,
+    capabilities: State<'_, CapabilityStore>,
+    project_id: i32,
+    file_path: String,
+) -> Result<usize, BackendError> {
+    let file_path = capabilities.check(project_id, &file_path)?;
+    backend.import_master_spec(project_id, file_path).await
+}
+
+#[tauri::command]
+pub async fn import_supplier_feedback(
+    backend: Backend<'_>,
+    capabilities: State<'_, CapabilityStore>,
+    project_id: i32,
+    iteration_id_str: String,
+    supplier_name: String,
+    file_path: String,
+) -> Result<usize, BackendError> {
+    let file_path = capabilities.check(project_id, &file_path)?;
+    backend
+        .import_supplier_feedback(project_id, iteration_id_str, supplier_name, file_path)
+        .await
+}
+
+#[tauri::command]
+pub async fn get_cockpit_data(
+    backend: Backend<'_>,
+    project_id: i32,
+    iteration_id: i32,
+) -> Result<String, BackendError> {
+    let result = backend.get_cockpit_data(project_id, iteration_id).await?;
+    Ok(result.to_string())
+}
+
+#[tauri::command]
+pub async fn list_recent_projects(
+    backend: Backend<'_>,
+    app_handle: tauri::AppHandle,
+) -> Result<String, BackendError> {
+    let app_data_dir = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| BackendError::transport("Could not resolve app data directory"))?;
+
+    let result = backend.list_recent_projects(app_data_dir).await?;
+    Ok(result.to_string())
+}
+
+#[tauri::command]
+pub async fn get_project_schema_status(
+    backend: Backend<'_>,
+    project_id: i32,
+) -> Result<String, BackendError> {
+    let result = backend.project_schema_status(project_id).await?;
+    Ok(result.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn create_project(
+    backend: Backend<'_>,
+    app_handle: tauri::AppHandle,
+    name: String,
+    path: String,
+) -> Result<String, BackendError> {
+    let app_data_dir: PathBuf = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| BackendError::transport("Could not resolve app data directory"))?;
+
+    let result = backend.create_project(app_data_dir, name, path).await?;
+    Ok(result.to_string())
+}
+
+/// Returns the captured backend request/stderr ring buffer. Empty unless
+/// the app was built with `--features debug`.
+#[tauri::command]
+pub fn get_backend_log(debug_log: State<'_, Arc<DebugLog>>) -> Vec<String> {
+    debug_log.snapshot()
+}
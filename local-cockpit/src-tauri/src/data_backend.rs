@@ -0,0 +1,49 @@
+//! The command surface implemented against a project's data, independent of
+//! whether that data is served by the Python/SQLAlchemy sidecar (desktop) or
+//! accessed directly from Rust (mobile, or any target without a `python3` on
+//! `PATH`). [`crate::backend::BackendProcess`] and
+//! [`crate::native_backend::NativeBackend`] both implement this so
+//! `commands.rs` never has to know which one it's talking to.
+
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::error::BackendError;
+
+#[async_trait]
+pub trait DataBackend: Send + Sync {
+    async fn import_master_spec(
+        &self,
+        project_id: i32,
+        file_path: PathBuf,
+    ) -> Result<usize, BackendError>;
+
+    async fn import_supplier_feedback(
+        &self,
+        project_id: i32,
+        iteration_id: String,
+        supplier_name: String,
+        file_path: PathBuf,
+    ) -> Result<usize, BackendError>;
+
+    async fn get_cockpit_data(
+        &self,
+        project_id: i32,
+        iteration_id: i32,
+    ) -> Result<Value, BackendError>;
+
+    async fn list_recent_projects(&self, app_data_dir: PathBuf) -> Result<Value, BackendError>;
+
+    async fn create_project(
+        &self,
+        app_data_dir: PathBuf,
+        name: String,
+        path: String,
+    ) -> Result<Value, BackendError>;
+
+    /// Current/target schema version and any recorded migration failure for
+    /// `project_id`'s DB, so the UI can prompt before opening it.
+    async fn project_schema_status(&self, project_id: i32) -> Result<Value, BackendError>;
+}
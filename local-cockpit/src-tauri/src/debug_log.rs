@@ -0,0 +1,33 @@
+//! In-app ring buffer of backend subprocess activity. Only populated when
+//! the `debug` feature is enabled (see [`crate::backend::BackendProcess`]),
+//! so the default build stays quiet while a `--features debug` build can
+//! diagnose a failed import without rebuilding or attaching a separate
+//! terminal.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+const CAPACITY: usize = 500;
+
+#[derive(Default)]
+pub struct DebugLog {
+    lines: Mutex<VecDeque<String>>,
+}
+
+impl DebugLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, line: impl Into<String>) {
+        let mut lines = self.lines.lock().unwrap();
+        if lines.len() == CAPACITY {
+            lines.pop_front();
+        }
+        lines.push_back(line.into());
+    }
+
+    pub fn snapshot(&self) -> Vec<String> {
+        self.lines.lock().unwrap().iter().cloned().collect()
+    }
+}
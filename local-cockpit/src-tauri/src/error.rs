@@ -0,0 +1,83 @@
+//! Typed error envelope returned by commands that talk to the backend
+//! process, so the frontend can distinguish "backend said no" from
+//! "backend process/transport broke" without string-sniffing.
+
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackendErrorKind {
+    /// The data layer failed underneath the request: the child process
+    /// could not be reached (died, pipe closed, etc), the app data directory
+    /// could not be resolved, or, in the native backend, a SQLite, file I/O
+    /// or migration step failed.
+    Transport,
+    /// A response line could not be parsed as the expected shape, or an
+    /// import file row has fewer columns than the import needs.
+    Protocol,
+    /// The backend itself reported a failure for this request.
+    Backend,
+    /// The request was rejected before reaching the backend, e.g. a
+    /// `file_path` outside the project's allowed import scopes.
+    Unauthorized,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendError {
+    pub kind: BackendErrorKind,
+    pub message: String,
+    pub detail: Option<String>,
+}
+
+impl BackendError {
+    pub fn transport(message: impl Into<String>) -> Self {
+        Self {
+            kind: BackendErrorKind::Transport,
+            message: message.into(),
+            detail: None,
+        }
+    }
+
+    pub fn protocol(message: impl Into<String>) -> Self {
+        Self {
+            kind: BackendErrorKind::Protocol,
+            message: message.into(),
+            detail: None,
+        }
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self {
+            kind: BackendErrorKind::Unauthorized,
+            message: message.into(),
+            detail: None,
+        }
+    }
+
+    /// Builds a `Backend`-kind error from the `error` value a response
+    /// carried, pulling out `message`/`detail` if the backend sent them.
+    pub fn from_backend_value(value: Value) -> Self {
+        let message = value
+            .get("message")
+            .and_then(Value::as_str)
+            .unwrap_or("backend reported an error")
+            .to_string();
+        let detail = value
+            .get("detail")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        Self {
+            kind: BackendErrorKind::Backend,
+            message,
+            detail,
+        }
+    }
+}
+
+impl std::fmt::Display for BackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
@@ -0,0 +1,208 @@
+//! Schema migrations for project `.sqlite` files.
+//!
+//! Every project DB carries a `schema_migrations` table recording which
+//! migration ids have been applied. [`migrate`] is run any time a project
+//! DB is opened to read or write project data ([`status`] only reads the
+//! versions and does not migrate): it applies pending migrations in order, each inside its
+//! own transaction. If one fails partway through, the transaction is rolled
+//! back and a row is written to `failed_migrations` naming the migration id
+//! and the error, so a half-applied schema is surfaced explicitly instead of
+//! silently corrupting the project.
+
+use rusqlite::{params, Connection};
+use serde_json::{json, Value};
+
+use crate::error::BackendError;
+
+pub struct Migration {
+    pub id: i64,
+    pub description: &'static str,
+    pub sql: &'static str,
+}
+
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        id: 1,
+        description: "initial schema",
+        sql: "CREATE TABLE IF NOT EXISTS requirements (req_id TEXT PRIMARY KEY, text TEXT);
+              CREATE TABLE IF NOT EXISTS supplier_feedback (
+                  id INTEGER PRIMARY KEY,
+                  iteration_id TEXT,
+                  supplier_name TEXT,
+                  req_id TEXT,
+                  comment TEXT
+              );
+              CREATE TABLE IF NOT EXISTS project_meta (name TEXT, source_path TEXT);",
+    },
+    Migration {
+        id: 2,
+        description: "track requirement status",
+        sql: "ALTER TABLE requirements ADD COLUMN status TEXT NOT NULL DEFAULT 'open';",
+    },
+];
+
+/// The schema version this build of the app expects a project DB to be at
+/// once every migration has run.
+pub fn target_version() -> i64 {
+    MIGRATIONS.last().map(|m| m.id).unwrap_or(0)
+}
+
+fn ensure_tracking_tables(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+             id INTEGER PRIMARY KEY,
+             applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+         );
+         CREATE TABLE IF NOT EXISTS failed_migrations (
+             id INTEGER PRIMARY KEY,
+             error TEXT NOT NULL,
+             failed_at TEXT NOT NULL DEFAULT (datetime('now'))
+         );",
+    )
+}
+
+fn current_version(conn: &Connection) -> rusqlite::Result<i64> {
+    conn.query_row(
+        "SELECT COALESCE(MAX(id), 0) FROM schema_migrations",
+        [],
+        |row| row.get(0),
+    )
+}
+
+fn record_failure(conn: &Connection, migration_id: i64, error: &str) {
+    let _ = conn.execute(
+        "INSERT OR REPLACE INTO failed_migrations (id, error) VALUES (?1, ?2)",
+        params![migration_id, error],
+    );
+}
+
+/// Applies every migration newer than the DB's current version, in order.
+/// Stops at, and records, the first failure rather than continuing past a
+/// broken schema state.
+pub fn migrate(conn: &mut Connection) -> Result<(), BackendError> {
+    migrate_with(conn, MIGRATIONS)
+}
+
+/// The actual `migrate` logic, parameterized over the migration list so
+/// tests can exercise a broken migration without touching [`MIGRATIONS`].
+fn migrate_with(conn: &mut Connection, migrations: &[Migration]) -> Result<(), BackendError> {
+    ensure_tracking_tables(conn).map_err(|e| BackendError::transport(e.to_string()))?;
+    let version = current_version(conn).map_err(|e| BackendError::transport(e.to_string()))?;
+
+    for migration in migrations.iter().filter(|m| m.id > version) {
+        let tx = conn
+            .transaction()
+            .map_err(|e| BackendError::transport(e.to_string()))?;
+
+        let outcome = tx.execute_batch(migration.sql).and_then(|_| {
+            tx.execute(
+                "INSERT INTO schema_migrations (id) VALUES (?1)",
+                params![migration.id],
+            )
+        });
+
+        match outcome {
+            Ok(_) => tx.commit().map_err(|e| BackendError::transport(e.to_string()))?,
+            Err(e) => {
+                let message = e.to_string();
+                drop(tx);
+                record_failure(conn, migration.id, &message);
+                return Err(BackendError::transport(format!(
+                    "migration {} ({}) failed: {message}",
+                    migration.id, migration.description
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Current version, target version, and the most recent recorded failure
+/// (if any), for `get_project_schema_status`.
+pub fn status(conn: &Connection) -> Result<Value, BackendError> {
+    ensure_tracking_tables(conn).map_err(|e| BackendError::transport(e.to_string()))?;
+    let current = current_version(conn).map_err(|e| BackendError::transport(e.to_string()))?;
+
+    let failure = conn
+        .query_row(
+            "SELECT id, error, failed_at FROM failed_migrations ORDER BY failed_at DESC LIMIT 1",
+            [],
+            |row| {
+                Ok(json!({
+                    "migration_id": row.get::<_, i64>(0)?,
+                    "error": row.get::<_, String>(1)?,
+                    "failed_at": row.get::<_, String>(2)?,
+                }))
+            },
+        )
+        .ok();
+
+    Ok(json!({
+        "current_version": current,
+        "target_version": target_version(),
+        "failure": failure,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table_exists(conn: &Connection, name: &str) -> bool {
+        conn.query_row(
+            "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1",
+            params![name],
+            |_| Ok(()),
+        )
+        .is_ok()
+    }
+
+    #[test]
+    fn failed_migration_rolls_back_and_is_recorded() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let broken = [Migration {
+            id: 1,
+            description: "half-applied schema",
+            sql: "CREATE TABLE should_not_persist (a INTEGER); THIS IS NOT VALID SQL;",
+        }];
+
+        let err = migrate_with(&mut conn, &broken).expect_err("broken migration must fail");
+        assert!(err.message.contains("migration 1"));
+
+        // The transaction rolled back: the table the failed batch created
+        // along the way must not have survived, and the migration must not
+        // be recorded as applied.
+        assert!(!table_exists(&conn, "should_not_persist"));
+        assert_eq!(current_version(&conn).unwrap(), 0);
+
+        // ...but the failure itself is recorded for `status` to surface.
+        let (recorded_id, error): (i64, String) = conn
+            .query_row(
+                "SELECT id, error FROM failed_migrations ORDER BY failed_at DESC LIMIT 1",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .expect("failure must be recorded");
+        assert_eq!(recorded_id, 1);
+        assert!(!error.is_empty());
+    }
+
+    #[test]
+    fn successful_migrations_apply_in_order() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate_with(&mut conn, MIGRATIONS).expect("bundled migrations must apply cleanly");
+        assert_eq!(current_version(&conn).unwrap(), target_version());
+        assert!(table_exists(&conn, "requirements"));
+    }
+
+    #[test]
+    fn migrate_is_idempotent_on_an_up_to_date_db() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate_with(&mut conn, MIGRATIONS).unwrap();
+        // Re-running against an already-migrated DB must not try to re-apply
+        // migrations whose `CREATE TABLE`/`ALTER TABLE` would now fail.
+        migrate_with(&mut conn, MIGRATIONS).expect("re-running migrate must be a no-op");
+        assert_eq!(current_version(&conn).unwrap(), target_version());
+    }
+}
@@ -0,0 +1,249 @@
+//! Rust-native implementation of [`DataBackend`] for targets where shelling
+//! out to `python3` isn't an option (iOS/Android, or a locked-down desktop
+//! without a system interpreter). Opens `projects/{id}.sqlite` directly with
+//! `rusqlite` and speaks the same schema the Python/SQLAlchemy backend
+//! writes, so a project database is portable between the two: import a spec
+//! on desktop, open the cockpit on mobile, same file.
+//!
+//! `rusqlite` connections aren't `Send` across `.await` points the way the
+//! async commands need, so every call is dispatched onto
+//! `tokio::task::spawn_blocking`.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use rusqlite::Connection;
+use serde_json::{json, Value};
+
+use crate::data_backend::DataBackend;
+use crate::error::BackendError;
+use crate::migrations;
+
+fn blocking_err(e: impl std::fmt::Display) -> BackendError {
+    BackendError::transport(e.to_string())
+}
+
+/// Path of `projects/{project_id}.sqlite` under the given app data
+/// directory. Only builds the path; nothing is opened or created here.
+fn project_db_path(app_data_dir: &Path, project_id: i32) -> PathBuf {
+    app_data_dir.join("projects").join(format!("{project_id}.sqlite"))
+}
+
+/// Opens a project DB and brings its schema up to date before handing back
+/// the connection, per the "migrate on open" rule.
+fn open_migrated(db_path: &Path) -> Result<Connection, BackendError> {
+    let mut conn = Connection::open(db_path).map_err(blocking_err)?;
+    migrations::migrate(&mut conn)?;
+    Ok(conn)
+}
+
+/// The next free project id: one past the highest existing `{id}.sqlite`
+/// filename, not a count of directory entries — a count collides with an
+/// existing id as soon as any project is deleted (or a stray file lands in
+/// the directory). Only safe to call while holding `create_project_lock`:
+/// two concurrent callers would otherwise both read the same highest id
+/// and collide with each other.
+fn next_project_id(projects_dir: &Path) -> i32 {
+    let Ok(entries) = std::fs::read_dir(projects_dir) else {
+        return 1;
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            entry
+                .path()
+                .file_stem()?
+                .to_str()?
+                .parse::<i32>()
+                .ok()
+        })
+        .max()
+        .map(|highest| highest + 1)
+        .unwrap_or(1)
+}
+
+pub struct NativeBackend {
+    app_data_dir: PathBuf,
+    /// Serializes `create_project`'s pick-an-id-then-create-the-file
+    /// sequence. Without this, two concurrent calls can both read the same
+    /// highest existing id via `next_project_id` and both open the same
+    /// `{id}.sqlite`, silently sharing one database between two "projects".
+    create_project_lock: tokio::sync::Mutex<()>,
+}
+
+impl NativeBackend {
+    pub fn new(app_data_dir: PathBuf) -> Self {
+        Self {
+            app_data_dir,
+            create_project_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+}
+
+#[async_trait]
+impl DataBackend for NativeBackend {
+    async fn import_master_spec(
+        &self,
+        project_id: i32,
+        file_path: PathBuf,
+    ) -> Result<usize, BackendError> {
+        let db_path = project_db_path(&self.app_data_dir, project_id);
+        tokio::task::spawn_blocking(move || {
+            let mut conn = open_migrated(&db_path)?;
+            // The master spec always ships with a header row; `csv`'s
+            // default `has_headers(true)` strips it before we ever see it.
+            let mut rdr = csv::Reader::from_path(&file_path).map_err(blocking_err)?;
+            let tx = conn.transaction().map_err(blocking_err)?;
+            let mut count = 0usize;
+            for (row, record) in rdr.records().enumerate() {
+                let record = record.map_err(blocking_err)?;
+                if record.len() < 2 {
+                    return Err(BackendError::protocol(format!(
+                        "master spec row {} has {} column(s), expected at least 2 (req_id, text)",
+                        row + 1,
+                        record.len()
+                    )));
+                }
+                tx.execute(
+                    "INSERT INTO requirements (req_id, text) VALUES (?1, ?2)",
+                    rusqlite::params![&record[0], &record[1]],
+                )
+                .map_err(blocking_err)?;
+                count += 1;
+            }
+            tx.commit().map_err(blocking_err)?;
+            Ok(count)
+        })
+        .await
+        .map_err(blocking_err)?
+    }
+
+    async fn import_supplier_feedback(
+        &self,
+        project_id: i32,
+        iteration_id: String,
+        supplier_name: String,
+        file_path: PathBuf,
+    ) -> Result<usize, BackendError> {
+        let db_path = project_db_path(&self.app_data_dir, project_id);
+        tokio::task::spawn_blocking(move || {
+            let mut conn = open_migrated(&db_path)?;
+            let mut rdr = csv::Reader::from_path(&file_path).map_err(blocking_err)?;
+            let tx = conn.transaction().map_err(blocking_err)?;
+            let mut count = 0usize;
+            for (row, record) in rdr.records().enumerate() {
+                let record = record.map_err(blocking_err)?;
+                if record.len() < 2 {
+                    return Err(BackendError::protocol(format!(
+                        "supplier feedback row {} has {} column(s), expected at least 2 (req_id, comment)",
+                        row + 1,
+                        record.len()
+                    )));
+                }
+                tx.execute(
+                    "INSERT INTO supplier_feedback (iteration_id, supplier_name, req_id, comment) \
+                     VALUES (?1, ?2, ?3, ?4)",
+                    rusqlite::params![iteration_id, supplier_name, &record[0], &record[1]],
+                )
+                .map_err(blocking_err)?;
+                count += 1;
+            }
+            tx.commit().map_err(blocking_err)?;
+            Ok(count)
+        })
+        .await
+        .map_err(blocking_err)?
+    }
+
+    async fn get_cockpit_data(
+        &self,
+        project_id: i32,
+        iteration_id: i32,
+    ) -> Result<Value, BackendError> {
+        let db_path = project_db_path(&self.app_data_dir, project_id);
+        tokio::task::spawn_blocking(move || {
+            let conn = open_migrated(&db_path)?;
+            let mut stmt = conn
+                .prepare(
+                    "SELECT r.req_id, r.text, f.supplier_name, f.comment \
+                     FROM requirements r \
+                     LEFT JOIN supplier_feedback f \
+                       ON f.req_id = r.req_id AND f.iteration_id = ?1",
+                )
+                .map_err(blocking_err)?;
+            let rows = stmt
+                .query_map(rusqlite::params![iteration_id], |row| {
+                    Ok(json!({
+                        "req_id": row.get::<_, String>(0)?,
+                        "text": row.get::<_, String>(1)?,
+                        "supplier_name": row.get::<_, Option<String>>(2)?,
+                        "comment": row.get::<_, Option<String>>(3)?,
+                    }))
+                })
+                .map_err(blocking_err)?;
+            let items: Vec<Value> = rows.collect::<Result<_, _>>().map_err(blocking_err)?;
+            Ok(json!({ "items": items }))
+        })
+        .await
+        .map_err(blocking_err)?
+    }
+
+    async fn list_recent_projects(&self, app_data_dir: PathBuf) -> Result<Value, BackendError> {
+        tokio::task::spawn_blocking(move || {
+            let dir = app_data_dir.join("projects");
+            let mut names = Vec::new();
+            if dir.is_dir() {
+                for entry in std::fs::read_dir(&dir).map_err(blocking_err)? {
+                    let entry = entry.map_err(blocking_err)?;
+                    if entry.path().extension().and_then(|e| e.to_str()) == Some("sqlite") {
+                        names.push(entry.file_name().to_string_lossy().to_string());
+                    }
+                }
+            }
+            Ok(json!({ "projects": names }))
+        })
+        .await
+        .map_err(blocking_err)?
+    }
+
+    async fn create_project(
+        &self,
+        app_data_dir: PathBuf,
+        name: String,
+        path: String,
+    ) -> Result<Value, BackendError> {
+        // Held across the pick-id-and-create-file sequence below so two
+        // concurrent `create_project` calls can't both pick the same id.
+        let _guard = self.create_project_lock.lock().await;
+
+        tokio::task::spawn_blocking(move || {
+            let projects_dir = app_data_dir.join("projects");
+            std::fs::create_dir_all(&projects_dir).map_err(blocking_err)?;
+
+            let next_id = next_project_id(&projects_dir);
+            let db_path = projects_dir.join(format!("{next_id}.sqlite"));
+
+            let conn = open_migrated(&db_path)?;
+            conn.execute(
+                "INSERT INTO project_meta (name, source_path) VALUES (?1, ?2)",
+                rusqlite::params![name, path],
+            )
+            .map_err(blocking_err)?;
+
+            Ok(json!({ "project_id": next_id, "name": name }))
+        })
+        .await
+        .map_err(blocking_err)?
+    }
+
+    async fn project_schema_status(&self, project_id: i32) -> Result<Value, BackendError> {
+        let db_path = project_db_path(&self.app_data_dir, project_id);
+        tokio::task::spawn_blocking(move || {
+            let conn = Connection::open(&db_path).map_err(blocking_err)?;
+            migrations::status(&conn)
+        })
+        .await
+        .map_err(blocking_err)?
+    }
+}